@@ -347,6 +347,8 @@ pub struct ConnectionOptions {
     pub download_interval: u64,
     pub pingback_timeout: u64,
     pub dns_timeout: u128,
+    pub dns_negative_cache_ttl: u128,
+    pub dns_max_failures: u64,
     pub max_inflight_blocks: u64,
     pub read_only_call_limit: ExecutionCost,
     pub maximum_call_argument_size: u32,
@@ -415,7 +417,9 @@ impl std::default::Default for ConnectionOptions {
             inv_sync_interval: INV_SYNC_INTERVAL, // how often to synchronize block inventories
             download_interval: BLOCK_DOWNLOAD_INTERVAL, // how often to scan for blocks to download
             pingback_timeout: 60,
-            dns_timeout: 15_000,    // DNS timeout, in millis
+            dns_timeout: 15_000,       // DNS timeout, in millis
+            dns_negative_cache_ttl: 0, // how long to remember a failed DNS lookup, in millis (0 disables)
+            dns_max_failures: 3, // how many consecutive failed DNS lookups to tolerate before temporarily blocking a data URL
             max_inflight_blocks: 6, // number of parallel block downloads
             read_only_call_limit: ExecutionCost {
                 write_length: 0,
@@ -93,6 +93,7 @@ impl PartialEq for DNSRequest {
 pub struct DNSResponse {
     pub request: DNSRequest,
     pub result: Result<Vec<SocketAddr>, String>,
+    pub cached_failure: bool, // true if this error came from the negative cache, not a lookup
 }
 
 impl DNSResponse {
@@ -100,6 +101,7 @@ impl DNSResponse {
         DNSResponse {
             request: request,
             result: result,
+            cached_failure: false,
         }
     }
 
@@ -107,6 +109,15 @@ impl DNSResponse {
         DNSResponse {
             request: request,
             result: Err(errstr),
+            cached_failure: false,
+        }
+    }
+
+    pub fn cached_error(request: DNSRequest, errstr: String) -> DNSResponse {
+        DNSResponse {
+            request: request,
+            result: Err(errstr),
+            cached_failure: true,
         }
     }
 }
@@ -118,6 +129,10 @@ pub struct DNSResolver {
     outbound: SyncSender<DNSResponse>,
     max_inflight: u64,
 
+    // failed lookups, and when we may try them again (in millis)
+    negative_cache: HashMap<(String, u16), u128>,
+    negative_cache_ttl: u128,
+
     // used mainly for testing
     hardcoded: HashMap<(String, u16), Vec<SocketAddr>>,
 }
@@ -140,6 +155,8 @@ impl DNSResolver {
             inbound: socket_chan_rx,
             outbound: dns_chan_tx,
             max_inflight: max_inflight,
+            negative_cache: HashMap::new(),
+            negative_cache_ttl: 0,
             hardcoded: HashMap::new(),
         };
         (resolver, client)
@@ -149,7 +166,42 @@ impl DNSResolver {
         self.hardcoded.insert((host.to_string(), port), addrs);
     }
 
-    pub fn resolve(&self, req: DNSRequest) -> DNSResponse {
+    /// Remember failed lookups for `ttl` milliseconds, so a name that keeps failing isn't
+    /// re-resolved on every request.  A TTL of 0 disables negative caching.
+    pub fn set_negative_cache_ttl(&mut self, ttl: u128) -> () {
+        self.negative_cache_ttl = ttl;
+        if ttl == 0 {
+            self.negative_cache.clear();
+        }
+    }
+
+    pub fn resolve(&mut self, req: DNSRequest) -> DNSResponse {
+        // forget failures that have expired, so names we never look up again don't pile up
+        let now = get_epoch_time_ms();
+        self.negative_cache.retain(|_, expires| *expires > now);
+
+        let key = (req.host.clone(), req.port);
+        if self.negative_cache.contains_key(&key) {
+            test_debug!(
+                "Resolve {}:{} failed recently; not retrying",
+                &req.host,
+                req.port
+            );
+            return DNSResponse::cached_error(
+                req,
+                "DNS resolve error: lookup failed recently".to_string(),
+            );
+        }
+
+        let resp = self.resolve_uncached(req);
+        if resp.result.is_err() && self.negative_cache_ttl > 0 {
+            self.negative_cache
+                .insert(key, get_epoch_time_ms() + self.negative_cache_ttl);
+        }
+        resp
+    }
+
+    fn resolve_uncached(&self, req: DNSRequest) -> DNSResponse {
         if let Some(ref addrs) = self.hardcoded.get(&(req.host.clone(), req.port)) {
             return DNSResponse::new(req, Ok(addrs.to_vec()));
         }
@@ -372,6 +424,7 @@ impl DNSClient {
 
 #[cfg(test)]
 mod test {
+    use super::*;
     use net::test::*;
     use std::collections::HashMap;
     use std::error::Error;
@@ -490,6 +543,56 @@ mod test {
         dns_thread_shutdown(client, thread_handle);
     }
 
+    #[test]
+    fn dns_resolve_negative_cache() {
+        let (mut resolver, _client) = DNSResolver::new(100);
+        resolver.set_negative_cache_ttl(1_000);
+
+        let resp = resolver.resolve(DNSRequest::new(
+            "asdfjkl;".to_string(),
+            80,
+            get_epoch_time_ms() + 120_000,
+        ));
+        assert!(resp.result.is_err());
+
+        // a real lookup would now succeed, but the failure is still cached
+        let addr: SocketAddr = "127.0.0.1:80".parse().unwrap();
+        resolver.add_hardcoded("asdfjkl;", 80, vec![addr.clone()]);
+        let resp = resolver.resolve(DNSRequest::new(
+            "asdfjkl;".to_string(),
+            80,
+            get_epoch_time_ms() + 120_000,
+        ));
+        assert!(resp.cached_failure);
+        assert!(resp
+            .result
+            .unwrap_err()
+            .find("lookup failed recently")
+            .is_some());
+
+        // other names are unaffected
+        let resp = resolver.resolve(DNSRequest::new(
+            "qwertyuiop;".to_string(),
+            80,
+            get_epoch_time_ms() + 120_000,
+        ));
+        assert!(!resp.cached_failure);
+        let err = resp.result.unwrap_err();
+        assert!(err.find("DNS resolve error").is_some());
+        assert!(err.find("lookup failed recently").is_none());
+        assert_eq!(resolver.negative_cache.len(), 2);
+
+        // once the TTL passes, the name is resolved again, and expired failures are forgotten
+        sleep_ms(1_500);
+        let resp = resolver.resolve(DNSRequest::new(
+            "asdfjkl;".to_string(),
+            80,
+            get_epoch_time_ms() + 120_000,
+        ));
+        assert_eq!(resp.result.unwrap(), vec![addr]);
+        assert_eq!(resolver.negative_cache.len(), 0);
+    }
+
     #[test]
     fn dns_resolve_no_such_name() {
         let (mut client, thread_handle) = dns_thread_start(100);
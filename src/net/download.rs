@@ -201,6 +201,10 @@ pub struct BlockDownloader {
     dns_lookups: HashMap<UrlString, Option<Vec<SocketAddr>>>,
     dns_timeout: u128,
 
+    /// Consecutive failed DNS lookups for each URL, and how many we tolerate before blocking it
+    dns_failures: HashMap<UrlString, u64>,
+    max_dns_failures: u64,
+
     /// In-flight requests for blocks and confirmed microblocks
     /// The key for each of these is the sortition height and _index_ block hash.
     getblock_requests: HashMap<BlockRequestKey, usize>,
@@ -226,6 +230,7 @@ pub struct BlockDownloader {
 impl BlockDownloader {
     pub fn new(
         dns_timeout: u128,
+        max_dns_failures: u64,
         download_interval: u64,
         max_inflight_requests: u64,
     ) -> BlockDownloader {
@@ -252,6 +257,8 @@ impl BlockDownloader {
             parsed_urls: HashMap::new(),
             dns_lookups: HashMap::new(),
             dns_timeout: dns_timeout,
+            dns_failures: HashMap::new(),
+            max_dns_failures: max_dns_failures,
 
             getblock_requests: HashMap::new(),
             getmicroblocks_requests: HashMap::new(),
@@ -305,14 +312,19 @@ impl BlockDownloader {
         &mut self,
         pox_id: &PoxId,
         dns_client: &mut DNSClient,
-        mut urls: Vec<UrlString>,
+        mut urls: HashSet<UrlString>,
     ) -> Result<(), net_error> {
         assert_eq!(self.state, BlockDownloaderState::DNSLookupBegin);
 
         // optimistic concurrency control: remember the current PoX Id
         self.pox_id = pox_id.clone();
         self.dns_lookups.clear();
-        for url_str in urls.drain(..) {
+
+        // only remember failures for URLs we're still trying
+        self.dns_failures
+            .retain(|url_str, _| urls.contains(url_str));
+
+        for url_str in urls.drain() {
             if url_str.len() == 0 {
                 continue;
             }
@@ -368,9 +380,31 @@ impl BlockDownloader {
                         match query_result.result {
                             Ok(addrs) => {
                                 *dns_result = Some(addrs);
+                                self.dns_failures.remove(url_str);
                             }
                             Err(msg) => {
                                 warn!("DNS failed to look up {:?}: {}", &url_str, msg);
+                                if query_result.cached_failure {
+                                    // no lookup was attempted, so don't count it
+                                    continue;
+                                }
+
+                                let failures =
+                                    self.dns_failures.entry(url_str.clone()).or_insert(0);
+                                *failures += 1;
+                                if *failures > self.max_dns_failures {
+                                    debug!(
+                                        "DNS failed to look up {:?} {} times in a row. Temporarily blocking URL",
+                                        &url_str, *failures
+                                    );
+                                    self.dns_failures.remove(url_str);
+
+                                    // don't try this again for a while
+                                    self.blocked_urls.insert(
+                                        url_str.clone(),
+                                        get_epoch_time_secs() + BLOCK_DOWNLOAD_BAN_URL,
+                                    );
+                                }
                             }
                         }
                     }
@@ -1636,12 +1670,7 @@ impl PeerNetwork {
                 }
             }
 
-            let mut urls = vec![];
-            for url in urlset.drain() {
-                urls.push(url);
-            }
-
-            downloader.dns_lookups_begin(&network.pox_id, dns_client, urls)
+            downloader.dns_lookups_begin(&network.pox_id, dns_client, urlset)
         })
     }
 
@@ -2114,6 +2143,7 @@ impl PeerNetwork {
     pub fn init_block_downloader(&mut self) -> () {
         self.block_downloader = Some(BlockDownloader::new(
             self.connection_opts.dns_timeout,
+            self.connection_opts.dns_max_failures,
             self.connection_opts.download_interval,
             self.connection_opts.max_inflight_blocks,
         ));
@@ -3166,6 +3196,72 @@ pub mod test {
         })
     }
 
+    fn run_failing_dns_lookup(
+        downloader: &mut BlockDownloader,
+        dns_client: &mut DNSClient,
+        url: &UrlString,
+    ) -> () {
+        let mut urls = HashSet::new();
+        urls.insert(url.clone());
+
+        downloader.state = BlockDownloaderState::DNSLookupBegin;
+        downloader
+            .dns_lookups_begin(&PoxId::initial(), dns_client, urls)
+            .unwrap();
+        while !downloader.dns_lookups_try_finish(dns_client).unwrap() {
+            sleep_ms(100);
+        }
+        assert_eq!(downloader.dns_lookups.get(url), Some(&None));
+    }
+
+    #[test]
+    pub fn test_dns_lookup_failures_block_url() {
+        use std::convert::TryFrom;
+
+        let (mut dns_client, thread_handle) = dns_thread_start(100);
+        let mut downloader = BlockDownloader::new(120_000, 3, 10, 6);
+        let url = UrlString::try_from("http://asdfjkl.invalid:20443".to_string()).unwrap();
+
+        // failures are tolerated up to the limit
+        for i in 0..3 {
+            run_failing_dns_lookup(&mut downloader, &mut dns_client, &url);
+            assert_eq!(downloader.dns_failures.get(&url), Some(&(i + 1)));
+            assert!(!downloader.blocked_urls.contains_key(&url));
+        }
+
+        // one more, and the URL is blocked
+        run_failing_dns_lookup(&mut downloader, &mut dns_client, &url);
+        assert!(downloader.dns_failures.get(&url).is_none());
+        assert!(*downloader.blocked_urls.get(&url).unwrap() > get_epoch_time_secs());
+
+        dns_thread_shutdown(dns_client, thread_handle);
+    }
+
+    #[test]
+    pub fn test_dns_lookup_failures_ignore_negative_cache() {
+        use std::convert::TryFrom;
+        use std::thread;
+
+        let (mut resolver, mut dns_client) = DNSResolver::new(100);
+        resolver.set_negative_cache_ttl(120_000);
+        let thread_handle = thread::spawn(move || {
+            resolver.thread_main();
+        });
+
+        let mut downloader = BlockDownloader::new(120_000, 3, 10, 6);
+        let url = UrlString::try_from("http://asdfjkl.invalid:20443".to_string()).unwrap();
+
+        // only the first lookup reaches the resolver; the rest are answered from the negative
+        // cache, and don't count towards blocking the URL
+        for _ in 0..6 {
+            run_failing_dns_lookup(&mut downloader, &mut dns_client, &url);
+            assert_eq!(downloader.dns_failures.get(&url), Some(&1));
+            assert!(!downloader.blocked_urls.contains_key(&url));
+        }
+
+        dns_thread_shutdown(dns_client, thread_handle);
+    }
+
     #[test]
     #[ignore]
     #[should_panic(expected = "blocked URL")]
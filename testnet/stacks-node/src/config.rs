@@ -584,6 +584,14 @@ impl Config {
                     dns_timeout: opts
                         .dns_timeout
                         .unwrap_or_else(|| HELIUM_DEFAULT_CONNECTION_OPTIONS.dns_timeout.clone()),
+                    dns_negative_cache_ttl: opts.dns_negative_cache_ttl.unwrap_or_else(|| {
+                        HELIUM_DEFAULT_CONNECTION_OPTIONS
+                            .dns_negative_cache_ttl
+                            .clone()
+                    }),
+                    dns_max_failures: opts.dns_max_failures.unwrap_or_else(|| {
+                        HELIUM_DEFAULT_CONNECTION_OPTIONS.dns_max_failures.clone()
+                    }),
                     max_inflight_blocks: opts.max_inflight_blocks.unwrap_or_else(|| {
                         HELIUM_DEFAULT_CONNECTION_OPTIONS
                             .max_inflight_blocks
@@ -948,6 +956,8 @@ pub struct ConnectionOptionsFile {
     pub soft_max_clients_per_host: Option<u64>,
     pub walk_interval: Option<u64>,
     pub dns_timeout: Option<u128>,
+    pub dns_negative_cache_ttl: Option<u128>,
+    pub dns_max_failures: Option<u64>,
     pub max_inflight_blocks: Option<u64>,
     pub read_only_call_limit_write_length: Option<u64>,
     pub read_only_call_limit_read_length: Option<u64>,
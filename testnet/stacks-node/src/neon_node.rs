@@ -251,6 +251,7 @@ fn spawn_peer(
 
     this.bind(p2p_sock, rpc_sock).unwrap();
     let (mut dns_resolver, mut dns_client) = DNSResolver::new(10);
+    dns_resolver.set_negative_cache_ttl(config.connection_options.dns_negative_cache_ttl);
     let sortdb = SortitionDB::open(&burn_db_path, false).map_err(NetError::DBError)?;
 
     let (mut chainstate, _) = StacksChainState::open_with_block_limit(